@@ -0,0 +1,213 @@
+use crate::{GameScreenDto, SubmitCommandDto, SubmitCommandResponse};
+use std::collections::HashMap;
+use std::fmt;
+
+const API_BASE: &str = "https://text-adventure.winsauce.com/api";
+
+/** Something went wrong talking to a [`GameBackend`]. */
+#[derive(Debug)]
+pub enum BackendError {
+    /** The request itself failed (connection refused, timed out, etc) */
+    Network(String),
+    /** A response was received but couldn't be understood */
+    Decode(String),
+    /** The operation was rejected (unsupported, or invalid for this backend's state) */
+    Invalid(String),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackendError::Network(message) => write!(f, "Network error: {}", message),
+            BackendError::Decode(message) => write!(f, "Decode error: {}", message),
+            BackendError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/** Anything that can serve game screens and resolve submitted commands.
+ * `Game` is generic over this so the HTTP client can be swapped out for a
+ * scripted [`MockBackend`] in tests, or a locally-authored [`WorldBackend`].
+ *
+ * The authoring methods default to rejecting the operation, since the
+ * hosted HTTP backend has no concept of authoring a world in-session; only
+ * [`WorldBackend`] overrides them. */
+pub trait GameBackend {
+    fn get_screen_by_id(&self, screen_id: &str) -> Result<GameScreenDto, BackendError>;
+    fn submit_command(&self, request: &SubmitCommandDto) -> Result<SubmitCommandResponse, BackendError>;
+
+    /** Creates a new screen and links an exit to it from `context_screen_id`. */
+    fn dig(&mut self, context_screen_id: &str, command: &str, new_screen_id: &str) -> Result<(), BackendError> {
+        let _ = (context_screen_id, command, new_screen_id);
+        return Err(BackendError::Invalid("This backend does not support authoring".to_string()));
+    }
+
+    /** Replaces the body of `context_screen_id`. */
+    fn set_description(&mut self, context_screen_id: &str, body: Vec<String>) -> Result<(), BackendError> {
+        let _ = (context_screen_id, body);
+        return Err(BackendError::Invalid("This backend does not support authoring".to_string()));
+    }
+
+    /** Links an exit from `context_screen_id` to an existing screen. */
+    fn link(&mut self, context_screen_id: &str, command: &str, target_screen_id: &str) -> Result<(), BackendError> {
+        let _ = (context_screen_id, command, target_screen_id);
+        return Err(BackendError::Invalid("This backend does not support authoring".to_string()));
+    }
+}
+
+/** The real backend - talks to the hosted text-adventure API over HTTP. */
+pub struct HttpBackend {
+    client: reqwest::blocking::Client,
+}
+
+impl HttpBackend {
+    pub fn new() -> HttpBackend {
+        return HttpBackend {
+            client: reqwest::blocking::Client::new(),
+        };
+    }
+}
+
+impl GameBackend for HttpBackend {
+    fn get_screen_by_id(&self, screen_id: &str) -> Result<GameScreenDto, BackendError> {
+        let request_url = format!("{API_BASE}/screen/{screen_id}");
+        let response = self
+            .client
+            .get(&request_url)
+            .send()
+            .map_err(|err| BackendError::Network(err.to_string()))?;
+
+        return response
+            .json::<GameScreenDto>()
+            .map_err(|err| BackendError::Decode(err.to_string()));
+    }
+
+    fn submit_command(&self, request: &SubmitCommandDto) -> Result<SubmitCommandResponse, BackendError> {
+        let response = self
+            .client
+            .post(format!("{API_BASE}/command"))
+            .json(request)
+            .send()
+            .map_err(|err| BackendError::Network(err.to_string()))?;
+
+        return response
+            .json::<SubmitCommandResponse>()
+            .map_err(|err| BackendError::Decode(err.to_string()));
+    }
+}
+
+/** An in-memory [`GameBackend`] for tests. Screens and command responses are
+ * registered up-front and served back out of a table, keyed by screen id or
+ * by `(context_screen_id, command)`. Command responses are stored as raw
+ * bytes rather than parsed DTOs, so tests can register deliberately
+ * malformed/partial JSON or non-UTF8 payloads and assert the client reports
+ * a [`BackendError`] instead of panicking. */
+#[cfg(test)]
+pub struct MockBackend {
+    screens: HashMap<String, GameScreenDto>,
+    command_responses: HashMap<(String, String), Vec<u8>>,
+}
+
+#[cfg(test)]
+impl MockBackend {
+    pub fn new() -> MockBackend {
+        return MockBackend {
+            screens: HashMap::new(),
+            command_responses: HashMap::new(),
+        };
+    }
+
+    /** Registers a screen so it can be fetched by id. */
+    pub fn with_screen(mut self, screen: GameScreenDto) -> MockBackend {
+        self.screens.insert(screen.id.clone(), screen);
+        return self;
+    }
+
+    /** Registers the raw response bytes to serve for a given command issued
+     * from a given screen. The bytes are not validated here - passing
+     * malformed JSON or invalid UTF-8 is a deliberate, supported use case. */
+    pub fn with_command_response(mut self, context_screen_id: &str, command: &str, raw_response: Vec<u8>) -> MockBackend {
+        self.command_responses
+            .insert((context_screen_id.to_string(), command.to_string()), raw_response);
+        return self;
+    }
+}
+
+#[cfg(test)]
+impl GameBackend for MockBackend {
+    fn get_screen_by_id(&self, screen_id: &str) -> Result<GameScreenDto, BackendError> {
+        return self
+            .screens
+            .get(screen_id)
+            .cloned()
+            .ok_or_else(|| BackendError::Network(format!("No mock screen registered for id '{}'", screen_id)));
+    }
+
+    fn submit_command(&self, request: &SubmitCommandDto) -> Result<SubmitCommandResponse, BackendError> {
+        let key = (request.context_screen_id.clone(), request.command.clone());
+        let raw_response = self
+            .command_responses
+            .get(&key)
+            .ok_or_else(|| BackendError::Network(format!("No mock response registered for command '{}'", request.command)))?;
+
+        let text = String::from_utf8(raw_response.clone())
+            .map_err(|err| BackendError::Decode(format!("Mock response was not valid UTF-8: {}", err)))?;
+
+        return serde_json::from_str::<SubmitCommandResponse>(&text)
+            .map_err(|err| BackendError::Decode(format!("Mock response was not valid JSON: {}", err)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serves_registered_screens() {
+        let backend = MockBackend::new().with_screen(GameScreenDto {
+            id: "screen-1".to_string(),
+            body: vec!["You are in a room.".to_string()],
+        });
+
+        let screen = backend.get_screen_by_id("screen-1").unwrap();
+        assert_eq!(screen.body, vec!["You are in a room.".to_string()]);
+    }
+
+    #[test]
+    fn errors_on_unregistered_screen() {
+        let backend = MockBackend::new();
+        assert!(backend.get_screen_by_id("missing").is_err());
+    }
+
+    #[test]
+    fn errors_instead_of_panicking_on_malformed_json() {
+        let backend = MockBackend::new().with_command_response("screen-1", "look", b"{ not json".to_vec());
+
+        let request = SubmitCommandDto {
+            context_screen_id: "screen-1".to_string(),
+            command: "look".to_string(),
+            state: String::new(),
+        };
+
+        match backend.submit_command(&request) {
+            Err(BackendError::Decode(_)) => {}
+            other => panic!("Expected a decode error, got {:?}", other.is_ok()),
+        }
+    }
+
+    #[test]
+    fn errors_instead_of_panicking_on_non_utf8_response() {
+        let backend = MockBackend::new().with_command_response("screen-1", "look", vec![0xff, 0xfe, 0xfd]);
+
+        let request = SubmitCommandDto {
+            context_screen_id: "screen-1".to_string(),
+            command: "look".to_string(),
+            state: String::new(),
+        };
+
+        match backend.submit_command(&request) {
+            Err(BackendError::Decode(_)) => {}
+            other => panic!("Expected a decode error, got {:?}", other.is_ok()),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::backend::BackendError;
+use std::fmt;
+
+/** Anything that can go wrong while playing a game session - talking to the
+ * backend, or making sense of the state it hands back. */
+#[derive(Debug)]
+pub enum GameError {
+    /** The backend couldn't be reached at all */
+    Network(String),
+    /** A response was received but couldn't be understood */
+    Decode(String),
+    /** A state string failed to decompress */
+    Decompress(String),
+    /** Decompressed state wasn't valid `ClientGameState` JSON */
+    Deserialize(String),
+    /** `ClientGameState` couldn't be serialised to JSON */
+    Serialize(String),
+    /** A state string failed to compress */
+    Compress(String),
+    /** The request itself was invalid - e.g. an authoring action aimed at a
+     * screen that doesn't exist in the world */
+    Invalid(String),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::Network(message) => write!(f, "Could not reach the game server: {}", message),
+            GameError::Decode(message) => write!(f, "Could not understand the server's response: {}", message),
+            GameError::Decompress(message) => write!(f, "Could not decompress game state: {}", message),
+            GameError::Deserialize(message) => write!(f, "Could not parse game state: {}", message),
+            GameError::Serialize(message) => write!(f, "Could not serialise game state: {}", message),
+            GameError::Compress(message) => write!(f, "Could not compress game state: {}", message),
+            GameError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<BackendError> for GameError {
+    fn from(err: BackendError) -> GameError {
+        match err {
+            BackendError::Network(message) => GameError::Network(message),
+            BackendError::Decode(message) => GameError::Decode(message),
+            BackendError::Invalid(message) => GameError::Invalid(message),
+        }
+    }
+}
@@ -1,10 +1,41 @@
+mod backend;
+mod error;
+mod session;
+mod world;
+
+use backend::{BackendError, GameBackend, HttpBackend};
+use error::GameError;
+use session::SavedSession;
+use world::WorldBackend;
 use serde::{Deserialize, Serialize};
+use std::fs;
 use std::io;
 use std::io::Write;
+use std::thread;
+use std::time::Duration;
 
 // Config
 const INITIAL_SCREEN_ID: &str = "0290922a-59ce-458b-8dbc-1c33f646580a";
-const API_BASE: &str = "https://text-adventure.winsauce.com/api";
+// How many times a failed network call is retried before giving up
+const MAX_RETRIES: u32 = 3;
+// Base delay for retry backoff; doubles on each subsequent retry
+const RETRY_BASE_DELAY_MS: u64 = 250;
+// How many commands the rolling transcript keeps before dropping the oldest
+const TRANSCRIPT_CAPACITY: usize = 100;
+// How many transcript entries `/history` prints at once
+const HISTORY_PAGE_SIZE: usize = 20;
+// Where the locally-authored world is stored when no path is given to `--offline`
+const DEFAULT_WORLD_PATH: &str = "world.json";
+
+/** Prints a line exactly as `println!` would, while also recording it into
+ * `$output` so it ends up in the session transcript. */
+macro_rules! emit {
+    ($output:expr, $($arg:tt)*) => {{
+        let line = format!($($arg)*);
+        println!("{}", line);
+        $output.push(line);
+    }};
+}
 
 // Types
 #[derive(Deserialize, Serialize)]
@@ -13,31 +44,30 @@ struct ClientGameState {
 }
 
 impl ClientGameState {
-    fn to_state_string(&self) -> String {
-        let json = serde_json::to_string(&self).unwrap();
-        let compressed_json = lz_str::compress_uri(&json)
-            .iter()
-            .map(|b| match char::from_u32(*b) {
-                Some(c) => c,
-                None => panic!("Non-char byte in compressed state string {}", b),
-            })
-            .collect::<String>();
-
-        return compressed_json;
+    fn to_state_string(&self) -> Result<String, GameError> {
+        let json = serde_json::to_string(&self).map_err(|err| GameError::Serialize(err.to_string()))?;
+
+        let mut compressed_json = String::new();
+        for b in lz_str::compress_uri(&json) {
+            match char::from_u32(b) {
+                Some(c) => compressed_json.push(c),
+                None => return Err(GameError::Compress(format!("Non-char byte in compressed state string {}", b))),
+            }
+        }
+
+        return Ok(compressed_json);
     }
 
-    fn from_state_string(state_string: &str) -> ClientGameState {
+    fn from_state_string(state_string: &str) -> Result<ClientGameState, GameError> {
         let raw_bytes = state_string.chars().map(|c| c as u32).collect::<Vec<u32>>();
-        let json = lz_str::decompress_uri(&raw_bytes).expect(&format!(
-            "Failed to decompress raw state string: {}",
-            &state_string
-        ));
-        return serde_json::from_str::<ClientGameState>(&json)
-            .expect(&format!("Failed to deserialise JSON state: {}", &json));
+        let json = lz_str::decompress_uri(&raw_bytes).ok_or_else(|| {
+            GameError::Decompress(format!("Failed to decompress raw state string: {}", &state_string))
+        })?;
+        return serde_json::from_str::<ClientGameState>(&json).map_err(|err| GameError::Deserialize(err.to_string()));
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 struct GameScreenDto {
     id: String,
     body: Vec<String>,
@@ -104,14 +134,84 @@ struct SubmitCommandFailureDto {
     message: String,
 }
 
+#[derive(Deserialize, Serialize)]
+struct CommandNode {
+    /** Delay before this command is submitted, if any */
+    delay: Option<Duration>,
+    /** The command text to submit */
+    command: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CommandList {
+    /** The first command to run, with no delay */
+    first: String,
+    /** Subsequent commands, each with an optional delay before it runs */
+    rest: Vec<CommandNode>,
+}
+
+/** One exchange in the session transcript: a submitted command (or slash
+ * command) and the lines that were printed in response. */
+struct TranscriptEntry {
+    command: String,
+    output: Vec<String>,
+}
+
+impl CommandList {
+    fn load(path: &str) -> Result<CommandList, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read script file '{}': {}", path, err))?;
+
+        // Prefer a JSON-encoded command list
+        if let Ok(list) = serde_json::from_str::<CommandList>(&contents) {
+            return Ok(list);
+        }
+
+        // Fall back to a simple line-based format: one command per line,
+        // optionally prefixed with a delay in milliseconds and a colon,
+        // e.g. `1000:look`. Blank lines and `#` comments are ignored.
+        let mut commands: Vec<CommandNode> = Vec::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (delay, command) = match line.split_once(':') {
+                Some((delay_str, command_str)) if !delay_str.is_empty() && delay_str.chars().all(|c| c.is_ascii_digit()) => {
+                    let delay_ms: u64 = delay_str.parse().unwrap_or(0);
+                    (Some(Duration::from_millis(delay_ms)), command_str.trim().to_string())
+                }
+                _ => (None, line.to_string()),
+            };
+
+            commands.push(CommandNode { delay, command });
+        }
+
+        if commands.is_empty() {
+            return Err(format!("Script file '{}' contained no commands", path));
+        }
+
+        let first = commands.remove(0).command;
+        Ok(CommandList { first, rest: commands })
+    }
+}
+
 struct Game {
     current_screen: GameScreenDto,
     current_state: ClientGameState,
-    client: reqwest::blocking::Client,
+    backend: Box<dyn GameBackend>,
+    /** Set when the last backend call failed with a network error, so the
+     * REPL can keep serving local slash commands and let the player know
+     * why their command didn't go anywhere. */
+    offline: bool,
+    /** A rolling record of every command and its printed output, oldest
+     * first, capped at `TRANSCRIPT_CAPACITY` entries. */
+    transcript: Vec<TranscriptEntry>,
 }
 
 impl Game {
-    fn new() -> Game {
+    fn new(backend: impl GameBackend + 'static) -> Game {
         return Game {
             current_screen: GameScreenDto {
                 id: String::new(),
@@ -120,37 +220,118 @@ impl Game {
             current_state: ClientGameState {
                 inventory: Vec::new(),
             },
-            client: reqwest::blocking::Client::new(),
+            backend: Box::new(backend),
+            offline: false,
+            transcript: Vec::new(),
         };
     }
 
-    fn get_screen_by_id(&self, screen_id: &str) -> Option<GameScreenDto> {
-        let request_url = format!("{API_BASE}/screen/{screen_id}");
-        let response = self.client.get(&request_url).send().unwrap();
-        return response.json::<GameScreenDto>().ok();
+    /** Appends a transcript entry, dropping the oldest one if the rolling
+     * transcript is at capacity. */
+    fn record(&mut self, command: &str, output: Vec<String>) {
+        if self.transcript.len() >= TRANSCRIPT_CAPACITY {
+            self.transcript.remove(0);
+        }
+        self.transcript.push(TranscriptEntry {
+            command: command.to_string(),
+            output,
+        });
     }
 
-    fn submit_command(&self, command: &str) -> SubmitCommandResponse {
+    fn get_screen_by_id(&mut self, screen_id: &str) -> Result<GameScreenDto, GameError> {
+        let result = with_retries(|| self.backend.get_screen_by_id(screen_id).map_err(GameError::from));
+        self.offline = matches!(result, Err(GameError::Network(_)));
+        return result;
+    }
+
+    fn submit_command(&mut self, command: &str) -> Result<SubmitCommandResponse, GameError> {
         let request: SubmitCommandDto = SubmitCommandDto {
             context_screen_id: self.current_screen.id.clone(), // @TODO is `clone()` the right answer here? don't want to move `id`
             command: String::from(command),
-            state: self.current_state.to_state_string(),
+            state: self.current_state.to_state_string()?,
         };
 
-        let response = self
-            .client
-            .post(format!("{API_BASE}/command"))
-            .json(&request)
-            .send()
-            .unwrap();
+        let result = with_retries(|| self.backend.submit_command(&request).map_err(GameError::from));
+        self.offline = matches!(result, Err(GameError::Network(_)));
+        return result;
+    }
+
+    /** Digs a new room and links an exit to it from the current screen.
+     * Only supported by backends that implement in-session authoring, such
+     * as [`WorldBackend`]. */
+    fn dig(&mut self, command: &str, new_screen_id: &str) -> Result<(), BackendError> {
+        let context_screen_id = self.current_screen.id.clone();
+        return self.backend.dig(&context_screen_id, command, new_screen_id);
+    }
+
+    /** Replaces the body of the current screen. */
+    fn set_description(&mut self, body: Vec<String>) -> Result<(), BackendError> {
+        let context_screen_id = self.current_screen.id.clone();
+        return self.backend.set_description(&context_screen_id, body);
+    }
+
+    /** Links an exit from the current screen to an existing one. */
+    fn link(&mut self, command: &str, target_screen_id: &str) -> Result<(), BackendError> {
+        let context_screen_id = self.current_screen.id.clone();
+        return self.backend.link(&context_screen_id, command, target_screen_id);
+    }
+}
 
-        return response.json::<SubmitCommandResponse>().unwrap();
+/** Retries a fallible backend call with exponential backoff when it fails
+ * with a transient network error. Decode errors aren't retried - if the
+ * server sent something unreadable, asking again won't change that. */
+fn with_retries<T>(mut attempt: impl FnMut() -> Result<T, GameError>) -> Result<T, GameError> {
+    let mut last_err = None;
+    for retry in 0..=MAX_RETRIES {
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(err @ GameError::Network(_)) => {
+                last_err = Some(err);
+                if retry < MAX_RETRIES {
+                    thread::sleep(Duration::from_millis(RETRY_BASE_DELAY_MS * 2u64.pow(retry)));
+                }
+            }
+            Err(err) => return Err(err),
+        }
     }
+    return Err(last_err.unwrap());
+}
+
+/** Fetches the starting screen, or exits the process with an error message -
+ * there's no sensible way to start the REPL without one. */
+fn load_initial_screen(game: &mut Game, screen_id: &str) -> GameScreenDto {
+    return match game.get_screen_by_id(screen_id) {
+        Ok(screen) => screen,
+        Err(err) => {
+            eprintln!("Failed to load the starting screen: {}", err);
+            std::process::exit(1);
+        }
+    };
 }
 
 fn main() {
-    let mut game = Game::new();
-    game.current_screen = game.get_screen_by_id(INITIAL_SCREEN_ID).unwrap();
+    // `--offline [world-file]` runs against a locally-authored world instead
+    // of the hosted API, so screens can be built without a server.
+    let mut args = std::env::args().skip(1);
+    let offline_world_path = match args.next() {
+        Some(flag) if flag == "--offline" => Some(args.next().unwrap_or_else(|| DEFAULT_WORLD_PATH.to_string())),
+        _ => None,
+    };
+
+    let mut game = match offline_world_path {
+        Some(path) => {
+            let backend = WorldBackend::load_or_create(&path);
+            let initial_screen_id = backend.initial_screen_id();
+            let mut game = Game::new(backend);
+            game.current_screen = load_initial_screen(&mut game, &initial_screen_id);
+            game
+        }
+        None => {
+            let mut game = Game::new(HttpBackend::new());
+            game.current_screen = load_initial_screen(&mut game, INITIAL_SCREEN_ID);
+            game
+        }
+    };
 
     // Print initial screen
     for line in &game.current_screen.body {
@@ -168,92 +349,369 @@ fn main() {
         // Read from stdin
         io::stdin().read_line(&mut user_input).unwrap();
 
-        // Evaluate input
-        match user_input.trim() {
-            "/inventory" => {
-                // Print inventory
-                println!("Current inventory:");
-                for inventory_item in &game.current_state.inventory {
-                    println!("  {}", &inventory_item);
-                }
+        if !handle_input(&mut game, user_input.trim()) {
+            break;
+        }
+    } // loop
+}
+
+/** Routes a single line of input (an interactive line, or one sourced from a
+ * `/run` script) through the slash commands and, failing that, the game's
+ * command dispatch. Returns `false` if the game should exit. */
+fn handle_input(game: &mut Game, input: &str) -> bool {
+    let mut output: Vec<String> = Vec::new();
+    // Housekeeping commands that inspect or export the transcript aren't
+    // themselves worth recording into it.
+    let mut should_record = true;
+
+    match input {
+        "/inventory" => {
+            // Print inventory
+            emit!(output, "Current inventory:");
+            for inventory_item in &game.current_state.inventory {
+                emit!(output, "  {}", &inventory_item);
             }
-            "/screen-id" | "/screen" => {
-                // Print the current screen's ID
-                println!("{}", &game.current_screen.id);
+        }
+        "/screen-id" | "/screen" => {
+            // Print the current screen's ID
+            emit!(output, "{}", &game.current_screen.id);
+        }
+        "/look" | "/whereami" | "/where" | "/repeat" | "/again" => {
+            // Re-print the current screen
+            for line in &game.current_screen.body {
+                emit!(output, "{}", &line);
             }
-            "/look" | "/whereami" | "/where" | "/repeat" | "/again" => {
-                // Re-print the current screen
-                for line in &game.current_screen.body {
-                    println!("{}", &line);
+        }
+        "/help" | "/?" => {
+            // Print help text
+            print_help_text();
+        }
+        "/exit" | "/quit" => {
+            // Exit game
+            return false;
+        }
+        _ if input == "/run" || input.starts_with("/run ") => {
+            // Run a saved sequence of commands from a file - each command it
+            // replays is recorded as its own transcript entry, so this one
+            // doesn't need its own.
+            let path = input.strip_prefix("/run").unwrap_or(input).trim();
+            if path.is_empty() {
+                println!("Usage: /run <file>");
+            } else {
+                match CommandList::load(path) {
+                    Ok(script) => {
+                        if !run_script(game, &script) {
+                            return false;
+                        }
+                    }
+                    Err(err) => println!("Could not run script: {}", err),
                 }
             }
-            "/help" | "/?" => {
-                // Print help text
-                print_help_text();
+            should_record = false;
+        }
+        _ if input == "/save" || input.starts_with("/save ") => {
+            // Snapshot the current screen and state to a named save slot
+            let name = input.strip_prefix("/save").unwrap_or(input).trim();
+            if name.is_empty() {
+                emit!(output, "Usage: /save <name>");
+            } else {
+                match game.current_state.to_state_string() {
+                    Ok(state) => match SavedSession::save(name, &game.current_screen, &state) {
+                        Ok(()) => emit!(output, "Saved session '{}'.", name),
+                        Err(err) => emit!(output, "Failed to save session '{}': {}", name, err),
+                    },
+                    Err(err) => emit!(output, "Failed to save session '{}': {}", name, err),
+                }
+            }
+        }
+        _ if input == "/load" || input.starts_with("/load ") => {
+            // Restore a named save slot
+            let name = input.strip_prefix("/load").unwrap_or(input).trim();
+            if name.is_empty() {
+                emit!(output, "Usage: /load <name>");
+            } else {
+                load_session(game, name, &mut output);
             }
-            "/exit" | "/quit" => {
-                // Exit game
-                break;
+        }
+        "/sessions" => {
+            // List saved session slots
+            match SavedSession::list() {
+                Ok(names) if names.is_empty() => emit!(output, "No saved sessions."),
+                Ok(names) => {
+                    emit!(output, "Saved sessions:");
+                    for name in &names {
+                        emit!(output, "  {}", name);
+                    }
+                }
+                Err(err) => emit!(output, "Failed to list sessions: {}", err),
+            }
+        }
+        "/history" => {
+            // Scroll back through recent exchanges
+            print_history(game);
+            should_record = false;
+        }
+        _ if input == "/dig" || input.starts_with("/dig ") => {
+            // Author a new room with an exit from here to it
+            let args = input.strip_prefix("/dig").unwrap_or(input).trim();
+            match args.split_once(' ') {
+                Some((command, new_screen_id)) if !new_screen_id.trim().is_empty() => {
+                    let (command, new_screen_id) = (command.trim(), new_screen_id.trim());
+                    match game.dig(command, new_screen_id) {
+                        Ok(()) => emit!(output, "Dug a new room '{}' with an exit '{}'.", new_screen_id, command),
+                        Err(err) => emit!(output, "Could not dig: {}", err),
+                    }
+                }
+                _ => emit!(output, "Usage: /dig <command> <new-screen-name>"),
             }
-            _ => {
-                // Anything else is treated as a command
-                match game.submit_command(&user_input.trim()) {
-                    SubmitCommandResponse::SubmitCommandPrintMessageSuccess(dto) => {
-                        // Message
-                        for line in &dto.print_message {
-                            println!("{}", line);
+        }
+        _ if input.starts_with("/desc") => {
+            // Edit the body of the current screen. Lines are separated by
+            // literal `\n` sequences, e.g. `/desc A dusty room.\nA door leads north.`
+            let text = input["/desc".len()..].trim();
+            if text.is_empty() {
+                emit!(output, "Usage: /desc <line 1>\\n<line 2>\\n...");
+            } else {
+                let body: Vec<String> = text.split("\\n").map(|line| line.to_string()).collect();
+                match game.set_description(body.clone()) {
+                    Ok(()) => {
+                        game.current_screen.body = body;
+                        emit!(output, "Updated the description of this screen.");
+                        for line in &game.current_screen.body {
+                            emit!(output, "{}", line);
                         }
-                        // Items added
-                        if dto.items_added.len() > 0 {
-                            println!("Items added:");
-                            for item_name in &dto.items_added {
-                                println!("+ {}", &item_name);
-                            }
+                    }
+                    Err(err) => emit!(output, "Could not update description: {}", err),
+                }
+            }
+        }
+        _ if input == "/link" || input.starts_with("/link ") => {
+            // Wire an exit from the current screen to an existing one
+            let args = input.strip_prefix("/link").unwrap_or(input).trim();
+            match args.split_once(' ') {
+                Some((command, target_screen_id)) if !target_screen_id.trim().is_empty() => {
+                    let (command, target_screen_id) = (command.trim(), target_screen_id.trim());
+                    match game.link(command, target_screen_id) {
+                        Ok(()) => emit!(output, "Linked '{}' to '{}'.", command, target_screen_id),
+                        Err(err) => emit!(output, "Could not link: {}", err),
+                    }
+                }
+                _ => emit!(output, "Usage: /link <command> <screen-id>"),
+            }
+        }
+        _ if input == "/export" || input.starts_with("/export ") => {
+            // Dump the transcript to a file
+            let path = input.strip_prefix("/export").unwrap_or(input).trim();
+            if path.is_empty() {
+                println!("Usage: /export <file>");
+            } else {
+                match export_transcript(game, path) {
+                    Ok(()) => println!("Exported session to '{}'.", path),
+                    Err(err) => println!("Failed to export session to '{}': {}", path, err),
+                }
+            }
+            should_record = false;
+        }
+        _ => {
+            // Anything else is treated as a command
+            let was_offline = game.offline;
+            match game.submit_command(input) {
+                Ok(SubmitCommandResponse::SubmitCommandPrintMessageSuccess(dto)) => {
+                    // Message
+                    for line in &dto.print_message {
+                        emit!(output, "{}", line);
+                    }
+                    // Items added
+                    if dto.items_added.len() > 0 {
+                        emit!(output, "Items added:");
+                        for item_name in &dto.items_added {
+                            emit!(output, "+ {}", &item_name);
                         }
-                        // Items removed
-                        if dto.items_removed.len() > 0 {
-                            println!("Items removed:");
-                            for item_name in &dto.items_removed {
-                                println!("- {}", &item_name);
-                            }
+                    }
+                    // Items removed
+                    if dto.items_removed.len() > 0 {
+                        emit!(output, "Items removed:");
+                        for item_name in &dto.items_removed {
+                            emit!(output, "- {}", &item_name);
                         }
+                    }
 
-                        // Update game's state
-                        game.current_state = ClientGameState::from_state_string(&dto.state);
+                    // Update game's state
+                    update_state(game, &dto.state);
+                }
+                Ok(SubmitCommandResponse::SubmitCommandNavigationSuccess(dto)) => {
+                    // Print new game screen
+                    for line in &dto.screen.body {
+                        emit!(output, "{}", &line);
                     }
-                    SubmitCommandResponse::SubmitCommandNavigationSuccess(dto) => {
-                        // Print new game screen
-                        for line in &dto.screen.body {
-                            println!("{}", &line);
-                        }
 
-                        // Items added
-                        if dto.items_added.len() > 0 {
-                            println!("Items added:");
-                            for item_name in &dto.items_added {
-                                println!("+ {}", &item_name);
-                            }
+                    // Items added
+                    if dto.items_added.len() > 0 {
+                        emit!(output, "Items added:");
+                        for item_name in &dto.items_added {
+                            emit!(output, "+ {}", &item_name);
                         }
-                        // Items removed
-                        if dto.items_removed.len() > 0 {
-                            println!("Items removed:");
-                            for item_name in &dto.items_removed {
-                                println!("- {}", &item_name);
-                            }
+                    }
+                    // Items removed
+                    if dto.items_removed.len() > 0 {
+                        emit!(output, "Items removed:");
+                        for item_name in &dto.items_removed {
+                            emit!(output, "- {}", &item_name);
                         }
-
-                        // Update game's state
-                        game.current_state = ClientGameState::from_state_string(&dto.state);
-                        game.current_screen = dto.screen;
                     }
-                    SubmitCommandResponse::SubmitCommandFailure(dto) => {
-                        // Failure - did not match any command
-                        println!("{}", &dto.message);
+
+                    // Update game's state
+                    update_state(game, &dto.state);
+                    game.current_screen = dto.screen;
+                }
+                Ok(SubmitCommandResponse::SubmitCommandFailure(dto)) => {
+                    // Failure - did not match any command
+                    emit!(output, "{}", &dto.message);
+                }
+                Err(err) => {
+                    // Couldn't reach the server (or couldn't make sense of what
+                    // it sent back) - report it but keep the REPL alive so
+                    // local slash commands still work.
+                    emit!(output, "{}", err);
+                    if !was_offline && game.offline {
+                        emit!(output, "You're offline - local commands like /inventory and /look still work.");
                     }
                 }
             }
+
+            if was_offline && !game.offline {
+                emit!(output, "Back online.");
+            }
         }
-    } // loop
+    }
+
+    if should_record {
+        game.record(input, output);
+    }
+
+    true
+}
+
+/** Prints the most recent entries of the rolling transcript. */
+fn print_history(game: &Game) {
+    if game.transcript.is_empty() {
+        println!("No history yet.");
+        return;
+    }
+
+    let start = game.transcript.len().saturating_sub(HISTORY_PAGE_SIZE);
+    println!("Recent history:");
+    for entry in &game.transcript[start..] {
+        println!("> {}", entry.command);
+        for line in &entry.output {
+            println!("  {}", line);
+        }
+    }
+}
+
+/** Dumps the session transcript to `path`. Files ending in `.json` are
+ * written as a `/run`-compatible [`CommandList`] (commands only, delays
+ * stripped); anything else gets a plain-text transcript. */
+fn export_transcript(game: &Game, path: &str) -> Result<(), String> {
+    let contents = if path.ends_with(".json") {
+        let commands: Vec<&String> = game.transcript.iter().map(|entry| &entry.command).collect();
+        let (first, rest) = commands
+            .split_first()
+            .ok_or_else(|| "Nothing to export yet".to_string())?;
+
+        let script = CommandList {
+            first: (*first).clone(),
+            rest: rest
+                .iter()
+                .map(|command| CommandNode {
+                    delay: None,
+                    command: (*command).clone(),
+                })
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&script).map_err(|err| format!("Failed to serialise script: {}", err))?
+    } else {
+        let mut text = String::new();
+        for entry in &game.transcript {
+            text.push_str(&format!("> {}\n", entry.command));
+            for line in &entry.output {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+        text
+    };
+
+    return fs::write(path, contents).map_err(|err| format!("Failed to write '{}': {}", path, err));
+}
+
+/** Parses a state string from the server and applies it to the game,
+ * printing a warning instead of crashing if the state was unreadable. */
+fn update_state(game: &mut Game, state_string: &str) {
+    match ClientGameState::from_state_string(state_string) {
+        Ok(state) => game.current_state = state,
+        Err(err) => println!("Warning: {}", err),
+    }
+}
+
+/** Restores a named save slot: re-fetches the saved screen and reconstructs
+ * the saved state, then re-prints the screen as if it had just loaded. */
+fn load_session(game: &mut Game, name: &str, output: &mut Vec<String>) {
+    let saved = match SavedSession::load(name) {
+        Ok(saved) => saved,
+        Err(err) => {
+            emit!(output, "Failed to load session '{}': {}", name, err);
+            return;
+        }
+    };
+
+    let screen = match game.get_screen_by_id(&saved.screen_id) {
+        Ok(screen) => screen,
+        Err(err) => {
+            emit!(output, "Failed to load session '{}': {}", name, err);
+            return;
+        }
+    };
+
+    let state = match ClientGameState::from_state_string(&saved.state) {
+        Ok(state) => state,
+        Err(err) => {
+            emit!(output, "Failed to load session '{}': {}", name, err);
+            return;
+        }
+    };
+
+    game.current_screen = screen;
+    game.current_state = state;
+
+    emit!(output, "Loaded session '{}'.", name);
+    for line in &game.current_screen.body {
+        emit!(output, "{}", line);
+    }
+}
+
+/** Runs a loaded `/run` script: each command is routed through the same
+ * dispatch path as interactive input, printed as if it had been typed.
+ * Returns `false` if a line (e.g. `/exit`) signaled the game should stop,
+ * so the caller can end the session instead of continuing to prompt. */
+fn run_script(game: &mut Game, script: &CommandList) -> bool {
+    println!("> {}", script.first);
+    if !handle_input(game, &script.first) {
+        return false;
+    }
+
+    for node in &script.rest {
+        if let Some(delay) = node.delay {
+            thread::sleep(delay);
+        }
+        println!("> {}", node.command);
+        if !handle_input(game, &node.command) {
+            return false;
+        }
+    }
+
+    return true;
 }
 
 fn print_help_text() {
@@ -281,6 +739,47 @@ List of commands:
 (alias: /?)
     Print this help message
 
+/run <path>
+    Run a saved sequence of
+    commands from a file, as
+    if they were typed in
+
+/save <name>
+    Save the current screen
+    and state to a named slot
+
+/load <name>
+    Restore a named save slot
+
+/sessions
+    List saved session slots
+
+/history
+    Scroll back through recent
+    commands and their output
+
+/export <file>
+    Export the session transcript.
+    Files ending .json export a
+    /run-compatible script;
+    anything else exports plain
+    text
+
+/dig <command> <new-screen-name>
+    (offline world mode only)
+    Create a new room and an
+    exit to it from here
+
+/desc <line 1>\\n<line 2>\\n...
+    (offline world mode only)
+    Set the description of the
+    current room
+
+/link <command> <screen-id>
+    (offline world mode only)
+    Add an exit from here to an
+    existing screen
+
 /exit
 (alias: /quit)
     Quit the game"
@@ -0,0 +1,92 @@
+use crate::GameScreenDto;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SESSIONS_DIR: &str = "sessions";
+
+/** A snapshot of a game session, small enough to round-trip through
+ * [`Game::get_screen_by_id`] and [`ClientGameState::from_state_string`] on
+ * load - just the screen the player was on, and their compressed state. */
+#[derive(Deserialize, Serialize)]
+pub struct SavedSession {
+    pub screen_id: String,
+    pub state: String,
+}
+
+impl SavedSession {
+    pub fn save(name: &str, screen: &GameScreenDto, state: &str) -> Result<(), String> {
+        let path = session_path(name)?;
+        fs::create_dir_all(SESSIONS_DIR).map_err(|err| format!("Failed to create sessions directory: {}", err))?;
+
+        let session = SavedSession {
+            screen_id: screen.id.clone(),
+            state: state.to_string(),
+        };
+        let json = serde_json::to_string_pretty(&session).expect("Failed to serialise session");
+
+        return fs::write(path, json).map_err(|err| format!("Failed to write session file: {}", err));
+    }
+
+    pub fn load(name: &str) -> Result<SavedSession, String> {
+        let contents = fs::read_to_string(session_path(name)?).map_err(|err| format!("Failed to read session file: {}", err))?;
+
+        return serde_json::from_str::<SavedSession>(&contents).map_err(|err| format!("Session file is corrupt: {}", err));
+    }
+
+    /** Lists the names of saved sessions, sorted alphabetically. */
+    pub fn list() -> Result<Vec<String>, String> {
+        if !Path::new(SESSIONS_DIR).exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(SESSIONS_DIR).map_err(|err| format!("Failed to read sessions directory: {}", err))?;
+
+        let mut names: Vec<String> = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|err| format!("Failed to read sessions directory: {}", err))?;
+            if let Some(name) = entry.path().file_stem().and_then(|stem| stem.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+
+        names.sort();
+        return Ok(names);
+    }
+}
+
+/** Rejects session names that could escape [`SESSIONS_DIR`] (path separators, `..`)
+ * before building the file path. */
+fn session_path(name: &str) -> Result<PathBuf, String> {
+    if name.contains('/') || name.contains('\\') || name.contains("..") {
+        return Err(format!("Invalid session name '{}'", name));
+    }
+
+    return Ok(Path::new(SESSIONS_DIR).join(format!("{}.json", name)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_a_plain_name() {
+        assert_eq!(session_path("my-save").unwrap(), Path::new(SESSIONS_DIR).join("my-save.json"));
+    }
+
+    #[test]
+    fn rejects_forward_slashes() {
+        assert!(session_path("sub/world").is_err());
+    }
+
+    #[test]
+    fn rejects_backslashes() {
+        assert!(session_path("sub\\world").is_err());
+    }
+
+    #[test]
+    fn rejects_parent_dir_segments() {
+        assert!(session_path("..").is_err());
+        assert!(session_path("../world").is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::backend::{BackendError, GameBackend};
+use crate::{GameScreenDto, SubmitCommandDto, SubmitCommandFailureDto, SubmitCommandNavigationSuccessDto, SubmitCommandResponse};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+const DEFAULT_INITIAL_SCREEN_ID: &str = "start";
+
+/** A single room in a locally-authored [`World`]: its body text, and a map
+ * of command -> target screen id for the exits dug or linked from it. */
+#[derive(Deserialize, Serialize)]
+struct WorldScreen {
+    body: Vec<String>,
+    exits: HashMap<String, String>,
+}
+
+/** A small MUD-style world: screens keyed by id, with exits between them.
+ * Serialized to a JSON file so it can be authored across sessions and later
+ * pushed to the server. */
+#[derive(Deserialize, Serialize)]
+struct World {
+    initial_screen_id: String,
+    screens: HashMap<String, WorldScreen>,
+}
+
+/** A [`GameBackend`] backed by a locally-authored [`World`] instead of the
+ * hosted API, so screens can be built without a server. Every authoring
+ * operation persists the world back to `path` immediately. */
+pub struct WorldBackend {
+    world: World,
+    path: String,
+}
+
+impl WorldBackend {
+    /** Loads the world from `path`, or creates a fresh one-room world and
+     * writes it there if the file doesn't exist yet or can't be parsed. */
+    pub fn load_or_create(path: &str) -> WorldBackend {
+        if let Ok(contents) = fs::read_to_string(path) {
+            if let Ok(world) = serde_json::from_str::<World>(&contents) {
+                return WorldBackend { world, path: path.to_string() };
+            }
+        }
+
+        let mut screens = HashMap::new();
+        screens.insert(
+            DEFAULT_INITIAL_SCREEN_ID.to_string(),
+            WorldScreen {
+                body: vec![
+                    "You are standing in an empty void, waiting to be described.".to_string(),
+                    "Use /desc to describe this room, and /dig <command> <new-screen-name> to create an exit.".to_string(),
+                ],
+                exits: HashMap::new(),
+            },
+        );
+
+        let backend = WorldBackend {
+            world: World {
+                initial_screen_id: DEFAULT_INITIAL_SCREEN_ID.to_string(),
+                screens,
+            },
+            path: path.to_string(),
+        };
+        let _ = backend.persist();
+        return backend;
+    }
+
+    pub fn initial_screen_id(&self) -> String {
+        return self.world.initial_screen_id.clone();
+    }
+
+    fn persist(&self) -> Result<(), BackendError> {
+        let json = serde_json::to_string_pretty(&self.world).map_err(|err| BackendError::Decode(err.to_string()))?;
+        return fs::write(&self.path, json).map_err(|err| BackendError::Network(err.to_string()));
+    }
+}
+
+impl GameBackend for WorldBackend {
+    fn get_screen_by_id(&self, screen_id: &str) -> Result<GameScreenDto, BackendError> {
+        return self
+            .world
+            .screens
+            .get(screen_id)
+            .map(|screen| GameScreenDto {
+                id: screen_id.to_string(),
+                body: screen.body.clone(),
+            })
+            .ok_or_else(|| BackendError::Invalid(format!("No such screen in the world: '{}'", screen_id)));
+    }
+
+    fn submit_command(&self, request: &SubmitCommandDto) -> Result<SubmitCommandResponse, BackendError> {
+        let screen = self
+            .world
+            .screens
+            .get(&request.context_screen_id)
+            .ok_or_else(|| BackendError::Invalid(format!("No such screen in the world: '{}'", request.context_screen_id)))?;
+
+        let normalized_command = request.command.trim().to_lowercase();
+        return match screen.exits.get(&normalized_command) {
+            Some(target_screen_id) => {
+                let target = self
+                    .world
+                    .screens
+                    .get(target_screen_id)
+                    .ok_or_else(|| BackendError::Invalid(format!("Exit points to a missing screen: '{}'", target_screen_id)))?;
+
+                Ok(SubmitCommandResponse::SubmitCommandNavigationSuccess(SubmitCommandNavigationSuccessDto {
+                    success: true,
+                    command_action_type: "navigate".to_string(),
+                    screen: GameScreenDto {
+                        id: target_screen_id.clone(),
+                        body: target.body.clone(),
+                    },
+                    state: request.state.clone(),
+                    items_added: Vec::new(),
+                    items_removed: Vec::new(),
+                }))
+            }
+            None => Ok(SubmitCommandResponse::SubmitCommandFailure(SubmitCommandFailureDto {
+                success: false,
+                message: format!("You can't '{}' here.", request.command),
+            })),
+        };
+    }
+
+    fn dig(&mut self, context_screen_id: &str, command: &str, new_screen_id: &str) -> Result<(), BackendError> {
+        if self.world.screens.contains_key(new_screen_id) {
+            return Err(BackendError::Invalid(format!("A screen named '{}' already exists", new_screen_id)));
+        }
+
+        let normalized_command = command.trim().to_lowercase();
+        let current = self
+            .world
+            .screens
+            .get(context_screen_id)
+            .ok_or_else(|| BackendError::Invalid(format!("No such screen in the world: '{}'", context_screen_id)))?;
+        if current.exits.contains_key(&normalized_command) {
+            return Err(BackendError::Invalid(format!("There's already an exit for '{}' here", command)));
+        }
+
+        self.world.screens.insert(
+            new_screen_id.to_string(),
+            WorldScreen {
+                body: vec![format!("A newly dug room named '{}'. Use /desc to describe it.", new_screen_id)],
+                exits: HashMap::new(),
+            },
+        );
+
+        // The entry above was verified to exist just before the insert
+        self.world
+            .screens
+            .get_mut(context_screen_id)
+            .unwrap()
+            .exits
+            .insert(normalized_command, new_screen_id.to_string());
+
+        return self.persist();
+    }
+
+    fn set_description(&mut self, context_screen_id: &str, body: Vec<String>) -> Result<(), BackendError> {
+        let screen = self
+            .world
+            .screens
+            .get_mut(context_screen_id)
+            .ok_or_else(|| BackendError::Invalid(format!("No such screen in the world: '{}'", context_screen_id)))?;
+        screen.body = body;
+
+        return self.persist();
+    }
+
+    fn link(&mut self, context_screen_id: &str, command: &str, target_screen_id: &str) -> Result<(), BackendError> {
+        if !self.world.screens.contains_key(target_screen_id) {
+            return Err(BackendError::Invalid(format!("No such screen: '{}'", target_screen_id)));
+        }
+
+        let normalized_command = command.trim().to_lowercase();
+        let current = self
+            .world
+            .screens
+            .get_mut(context_screen_id)
+            .ok_or_else(|| BackendError::Invalid(format!("No such screen in the world: '{}'", context_screen_id)))?;
+        if current.exits.contains_key(&normalized_command) {
+            return Err(BackendError::Invalid(format!("There's already an exit for '{}' here", command)));
+        }
+
+        current.exits.insert(normalized_command, target_screen_id.to_string());
+
+        return self.persist();
+    }
+}